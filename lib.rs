@@ -20,8 +20,9 @@
 //! You can then use `+`, etc. on the checked variables, and then deref the result to get an
 //! `Option<_>`.
 //! 
-//! You can also use numeric literals/unchecked values, so long as they are on the right side of
-//! the operation.
+//! You can also use numeric literals/unchecked values on either side of `+`, `-`, `*`, `/` and
+//! `%`; an unchecked left-hand value must be a primitive integer type. Shifts (`<<`, `>>`) take
+//! an unchecked `u32` on the right side only.
 //! 
 //! ```
 //! # #[macro_use] extern crate cheque;
@@ -41,20 +42,25 @@
 //! `where` bounds.
 //! [checked num_traits]: http://rust-num.github.io/num/num_traits/ops/checked/index.html
 
+#![no_std]
+
 extern crate num_traits;
 
-use std::ops::*;
-use std::cmp::PartialEq;
+use core::ops::*;
+use core::cmp::PartialEq;
 
 use num_traits::ops::checked::*;
+use num_traits::{NumCast, ToPrimitive};
 
 #[macro_export]
 macro_rules! let_checked {
     ($($ident:ident),*) => {$(
-        let $ident = $crate::Checker(Some($ident));
+        #[allow(unused_mut)]
+        let mut $ident = $crate::Checker(Some($ident));
     )*};
     ($($ident:ident,)*) => {$(
-        let $ident = $crate::Checker(Some($ident));
+        #[allow(unused_mut)]
+        let mut $ident = $crate::Checker(Some($ident));
     )*};
 }
 
@@ -71,6 +77,13 @@ impl<T> DerefMut for Checker<T> {
     fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
 }
 
+impl<T: ToPrimitive> Checker<T> {
+    /// Checked numeric cast to another type, yielding `None` if the value doesn't fit `U`.
+    pub fn cast<U: NumCast>(self) -> Checker<U> {
+        Checker(self.0.and_then(|v| U::from(v)))
+    }
+}
+
 impl<T> PartialEq<T> for Checker<T>
 where T: PartialEq<T> + Copy
 {
@@ -119,6 +132,94 @@ impl_checked![Add, add, CheckedAdd, checked_add];
 impl_checked![Sub, sub, CheckedSub, checked_sub];
 impl_checked![Mul, mul, CheckedMul, checked_mul];
 impl_checked![Div, div, CheckedDiv, checked_div];
+impl_checked![Rem, rem, CheckedRem, checked_rem];
+
+macro_rules! impl_checked_shift {
+    ($Vanilla:ident, $vanilla_fn:ident, $Checked:ident, $checked_fn:ident) => {
+        impl<T> $Vanilla<u32> for Checker<T>
+        where T: $Checked
+        {
+            type Output = Self;
+            #[inline]
+            fn $vanilla_fn(self, rhs: u32) -> Self {
+                Checker(self.0.and_then(|l| l.$checked_fn(rhs)))
+            }
+        }
+    }
+}
+
+impl_checked_shift![Shl, shl, CheckedShl, checked_shl];
+impl_checked_shift![Shr, shr, CheckedShr, checked_shr];
+
+impl<T> Neg for Checker<T>
+where T: CheckedNeg
+{
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Checker(self.0.and_then(|l| l.checked_neg()))
+    }
+}
+
+// The orphan rule means we can't write `impl<T> Add<Checker<T>> for T`, so the left-hand
+// impls are stamped out per primitive instead.
+macro_rules! impl_checked_lhs {
+    ($Vanilla:ident, $vanilla_fn:ident, $checked_fn:ident, $($t:ty),*) => {$(
+        impl $Vanilla<Checker<$t>> for $t {
+            type Output = Checker<$t>;
+            #[inline]
+            fn $vanilla_fn(self, rhs: Checker<$t>) -> Checker<$t> {
+                Checker(rhs.0.and_then(|r| self.$checked_fn(r)))
+            }
+        }
+    )*}
+}
+
+macro_rules! impl_checked_lhs_all {
+    ($($t:ty),*) => {
+        impl_checked_lhs![Add, add, checked_add, $($t),*];
+        impl_checked_lhs![Sub, sub, checked_sub, $($t),*];
+        impl_checked_lhs![Mul, mul, checked_mul, $($t),*];
+        impl_checked_lhs![Div, div, checked_div, $($t),*];
+        impl_checked_lhs![Rem, rem, checked_rem, $($t),*];
+    }
+}
+
+impl_checked_lhs_all![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+macro_rules! impl_checked_assign {
+    ($Vanilla:ident, $vanilla_fn:ident, $Checked:ident, $checked_fn:ident) => {
+        impl<T> $Vanilla<Self> for Checker<T>
+        where T: $Checked
+        {
+            #[inline]
+            fn $vanilla_fn(&mut self, rhs: Checker<T>) {
+                self.0 = match (self.0.take(), rhs.0) {
+                    (Some(l), Some(r)) => l.$checked_fn(&r),
+                    _ => None,
+                };
+            }
+        }
+
+        impl<T> $Vanilla<T> for Checker<T>
+        where T: $Checked
+        {
+            #[inline]
+            fn $vanilla_fn(&mut self, rhs: T) {
+                self.0 = self.0.take().and_then(|l| l.$checked_fn(&rhs));
+            }
+        }
+    }
+}
+
+impl_checked_assign![AddAssign, add_assign, CheckedAdd, checked_add];
+impl_checked_assign![SubAssign, sub_assign, CheckedSub, checked_sub];
+impl_checked_assign![MulAssign, mul_assign, CheckedMul, checked_mul];
+impl_checked_assign![DivAssign, div_assign, CheckedDiv, checked_div];
+impl_checked_assign![RemAssign, rem_assign, CheckedRem, checked_rem];
 
 
 #[cfg(test)]
@@ -131,10 +232,12 @@ mod test {
         a - a;
         a * a;
         a / a;
+        a % a;
         a + 1;
         a - 1;
         a * 1;
         a / 1;
+        a % 2;
     }
 
     #[test]
@@ -145,6 +248,66 @@ mod test {
         assert_eq!(b / z, None);
     }
 
+    #[test]
+    fn remainder_by_zero() {
+        let b = 1u8;
+        let z = 0u8;
+        let_checked![b, z];
+        assert_eq!(b % z, None);
+    }
+
+    #[test]
+    fn overshift() {
+        let b = 1u8;
+        let_checked![b];
+        assert_eq!(b << 8, None);
+        assert_eq!(b >> 8, None);
+        assert_eq!(b << 1, 2);
+    }
+
+    #[test]
+    fn negate() {
+        let a = 1i8;
+        let m = i8::MIN;
+        let_checked![a, m];
+        assert_eq!(-a, -1);
+        assert_eq!(-m, None);
+    }
+
+    #[test]
+    fn lhs_primitive() {
+        let a = 10u8;
+        let_checked![a];
+        assert_eq!(100 - a, 90);
+        assert_eq!(1 - a, None);
+    }
+
+    #[test]
+    fn compound_assign() {
+        let a = 10u8;
+        let b = 5u8;
+        let_checked![a, b];
+        a += b;
+        assert_eq!(a, 15);
+        a *= 2;
+        assert_eq!(a, 30);
+        a -= 255;
+        assert_eq!(a, None);
+        a += 1;
+        assert_eq!(a, None);
+    }
+
+    #[test]
+    fn cast() {
+        let a = 10u32;
+        let_checked![a];
+        assert_eq!(a.cast::<u8>(), 10u8);
+
+        let b = 1000u32;
+        let_checked![b];
+        assert_eq!(b.cast::<u8>(), None);
+    }
+
     #[test]
     fn empty_invoke() {
         let_checked![]; //... how does it disambiguate? o_O